@@ -0,0 +1,122 @@
+use serde_derive::Deserialize;
+use std::collections::HashMap;
+use std::fs;
+
+/// Settings for a single OpenAI-compatible API endpoint (OpenAI itself, Azure OpenAI,
+/// perplexity.ai, a local llama server, ...), as configured under `[clients.<name>]`
+/// in `config.toml`.
+#[derive(Deserialize, Debug, Clone)]
+pub struct ClientConfig {
+    pub api_base: String,
+    #[serde(default)]
+    pub api_key: Option<String>,
+    pub model: String,
+    #[serde(default)]
+    pub organization_id: Option<String>,
+}
+
+impl Default for ClientConfig {
+    fn default() -> Self {
+        ClientConfig {
+            api_base: "https://api.openai.com/v1".to_string(),
+            api_key: None,
+            model: "gpt-3.5-turbo".to_string(),
+            organization_id: None,
+        }
+    }
+}
+
+#[derive(Deserialize, Debug, Default)]
+struct RawConfig {
+    #[serde(default)]
+    default_client: Option<String>,
+    #[serde(default)]
+    clients: HashMap<String, ClientConfig>,
+    #[serde(default)]
+    extra: ExtraConfig,
+}
+
+/// Connection-level settings that apply regardless of which client is selected: routing
+/// through a proxy and bounding how long a stalled connection attempt is allowed to hang.
+#[derive(Deserialize, Debug, Clone, Default)]
+pub struct ExtraConfig {
+    pub proxy: Option<String>,
+    pub connect_timeout: Option<u64>,
+}
+
+/// Picks the proxy URI to use: the config file's `proxy` wins, then `HTTPS_PROXY`, then
+/// `ALL_PROXY`, matching the convention most HTTP tooling already follows.
+fn resolve_proxy(
+    configured: Option<String>,
+    https_proxy: Option<String>,
+    all_proxy: Option<String>,
+) -> Option<String> {
+    configured.or(https_proxy).or(all_proxy)
+}
+
+/// Resolves the `[extra]` section from `config.toml`. `HTTPS_PROXY`/`ALL_PROXY` are used
+/// as a fallback when `proxy` isn't set in the file, matching the convention most HTTP
+/// tooling already follows.
+pub fn load_extra() -> ExtraConfig {
+    let raw: RawConfig = fs::read_to_string("config.toml")
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default();
+
+    let mut extra = raw.extra;
+    extra.proxy = resolve_proxy(
+        extra.proxy,
+        std::env::var("HTTPS_PROXY").ok(),
+        std::env::var("ALL_PROXY").ok(),
+    );
+    extra
+}
+
+/// Picks which client name to look up: an explicit `--client`/`/client` name wins, then
+/// the config file's `default_client`, then the hardcoded "openai" fallback.
+fn resolve_client_name(explicit: Option<&str>, default_client: Option<&str>) -> String {
+    explicit.or(default_client).unwrap_or("openai").to_string()
+}
+
+/// Resolves the named client's settings from `config.toml`; with no name given, the
+/// config's `default_client` and then an `openai` entry are looked up instead. Falls back
+/// to plain OpenAI defaults when no config file exists or no client by the resolved name is
+/// defined, so existing behavior is preserved for users who never set one up.
+///
+/// The `OPENAI_KEY` environment variable always overrides `api_key`, so secrets never
+/// have to live in `config.toml`.
+pub fn load_client(name: Option<&str>) -> ClientConfig {
+    let raw: RawConfig = fs::read_to_string("config.toml")
+        .ok()
+        .and_then(|contents| toml::from_str(&contents).ok())
+        .unwrap_or_default();
+
+    let client_name = resolve_client_name(name, raw.default_client.as_deref());
+    let mut client = raw.clients.get(&client_name).cloned().unwrap_or_default();
+
+    if let Ok(key) = std::env::var("OPENAI_KEY") {
+        client.api_key = Some(key);
+    }
+
+    client
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn resolve_client_name_prefers_explicit_over_default() {
+        assert_eq!(resolve_client_name(Some("azure"), Some("local")), "azure");
+    }
+
+    #[test]
+    fn resolve_client_name_falls_back_to_default_client() {
+        assert_eq!(resolve_client_name(None, Some("local")), "local");
+    }
+
+    #[test]
+    fn resolve_client_name_falls_back_to_openai() {
+        assert_eq!(resolve_client_name(None, None), "openai");
+    }
+}